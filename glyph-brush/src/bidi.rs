@@ -0,0 +1,213 @@
+use super::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BidiClass {
+    /// `0` = LTR, `1` = RTL.
+    Strong(u8),
+    /// European/Arabic-Indic digits (rules EN/AN) — take the surrounding
+    /// embedding level for positioning, but rule I2 bumps their own level by one
+    /// when that surrounding level is odd (RTL), so L2's per-level reversal passes
+    /// cancel back out and the digits keep their own left-to-right reading order.
+    Number,
+    Neutral,
+}
+
+fn classify(c: char) -> BidiClass {
+    let is_rtl = matches!(c as u32,
+        0x0590..=0x05FF // Hebrew
+        | 0x0600..=0x06FF // Arabic
+        | 0x0750..=0x077F // Arabic Supplement
+        | 0x08A0..=0x08FF // Arabic Extended-A
+        | 0xFB1D..=0xFDFF // Hebrew/Arabic presentation forms A
+        | 0xFE70..=0xFEFF // Arabic presentation forms B
+    );
+    let is_number = matches!(c as u32,
+        0x0030..=0x0039 // European digits 0-9
+        | 0x0660..=0x0669 // Arabic-Indic digits
+        | 0x06F0..=0x06F9 // Extended Arabic-Indic digits
+    );
+    if is_number {
+        BidiClass::Number
+    } else if is_rtl {
+        BidiClass::Strong(1)
+    } else if c.is_alphabetic() {
+        BidiClass::Strong(0)
+    } else {
+        BidiClass::Neutral
+    }
+}
+
+fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F // Combining Diacritical Marks
+        | 0x0483..=0x0489 // Cyrillic combining marks
+        | 0x0591..=0x05BD // Hebrew points
+        | 0x0610..=0x061A // Arabic marks
+        | 0x064B..=0x065F // Arabic combining marks
+    )
+}
+
+const MIRROR_PAIRS: &[(char, char)] = &[
+    ('(', ')'),
+    (')', '('),
+    ('[', ']'),
+    (']', '['),
+    ('{', '}'),
+    ('}', '{'),
+    ('<', '>'),
+    ('>', '<'),
+];
+
+fn mirror(c: char) -> char {
+    MIRROR_PAIRS
+        .iter()
+        .find(|&&(from, _)| from == c)
+        .map_or(c, |&(_, to)| to)
+}
+
+/// A grapheme-ish cluster: a base char plus any directly-following combining marks,
+/// kept together through reordering so marks never separate from their base.
+struct Cluster {
+    /// `(byte_index, char)` pairs in logical (source) order; index 0 is the base char.
+    chars: Vec<(usize, char)>,
+    level: u8,
+}
+
+/// Computes the visual order of `text`'s chars under `base_direction`, per a
+/// simplified UAX #9: paragraph level resolution (rule P2/P3), per-cluster
+/// embedding levels (rules W*/N* collapsed to "neutrals take the preceding
+/// resolved level"), L2 run reversal, and L4 bracket mirroring. Combining marks
+/// stay attached to their base character's cluster so they're never reordered
+/// independently of it (this crate has no full canonical-combining-class table,
+/// so only the common ranges above are recognised).
+///
+/// Returns `(byte_index, mirrored_char)` pairs in left-to-right visual order,
+/// where `mirrored_char` is `None` unless the source char was mirrored because it
+/// landed in a right-to-left run.
+pub(crate) fn reorder_visual(text: &str, base_direction: Direction) -> Vec<(usize, Option<char>)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    if chars.is_empty() {
+        return vec![];
+    }
+
+    let mut clusters: Vec<Cluster> = Vec::new();
+    for &(idx, c) in &chars {
+        if is_combining_mark(c) {
+            if let Some(last) = clusters.last_mut() {
+                last.chars.push((idx, c));
+                continue;
+            }
+        }
+        clusters.push(Cluster {
+            chars: vec![(idx, c)],
+            level: 0,
+        });
+    }
+
+    // P2/P3: the paragraph level is Ltr/Rtl if forced, else the level of the first
+    // strong-directional char, else Ltr.
+    let base_level = match base_direction {
+        Direction::Ltr => 0,
+        Direction::Rtl => 1,
+        Direction::Auto => chars
+            .iter()
+            .find_map(|&(_, c)| match classify(c) {
+                BidiClass::Strong(level) => Some(level),
+                BidiClass::Number | BidiClass::Neutral => None,
+            })
+            .unwrap_or(0),
+    };
+
+    // W*/N* (simplified): strong chars take their own level; neutrals (and
+    // combining marks, via their base char) inherit the preceding resolved level;
+    // numbers inherit it too but bump by one (rule I2) when it's odd, so a
+    // contiguous digit run nests one level above the RTL text around it.
+    let mut prev_level = base_level;
+    for cluster in &mut clusters {
+        let base_char = cluster.chars[0].1;
+        cluster.level = match classify(base_char) {
+            BidiClass::Strong(level) => level,
+            BidiClass::Number if prev_level % 2 == 1 => prev_level + 1,
+            BidiClass::Number | BidiClass::Neutral => prev_level,
+        };
+        prev_level = cluster.level;
+    }
+
+    // L2: from the highest level down to the lowest odd level, reverse every
+    // contiguous run of clusters whose level is >= that level.
+    let max_level = clusters.iter().map(|c| c.level).max().unwrap_or(0);
+    let mut order: Vec<usize> = (0..clusters.len()).collect();
+    let mut level = max_level;
+    while level >= 1 {
+        let mut start = 0;
+        while start < order.len() {
+            if clusters[order[start]].level >= level {
+                let mut end = start + 1;
+                while end < order.len() && clusters[order[end]].level >= level {
+                    end += 1;
+                }
+                order[start..end].reverse();
+                start = end;
+            } else {
+                start += 1;
+            }
+        }
+        level -= 1;
+    }
+
+    // L4: mirror bracket/paren glyphs that ended up in an RTL (odd-level) position.
+    let mut visual = Vec::with_capacity(chars.len());
+    for &cluster_idx in &order {
+        let cluster = &clusters[cluster_idx];
+        for &(idx, c) in &cluster.chars {
+            let mirrored = (cluster.level % 2 == 1 && mirror(c) != c).then(|| mirror(c));
+            visual.push((idx, mirrored));
+        }
+    }
+
+    visual
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Maps a `reorder_visual` result back to chars (mirrored where applicable) for
+    /// easy comparison against an expected visual string.
+    fn visual_chars(text: &str, base_direction: Direction) -> Vec<char> {
+        let by_idx: std::collections::HashMap<usize, char> = text.char_indices().collect();
+        reorder_visual(text, base_direction)
+            .into_iter()
+            .map(|(idx, mirrored)| mirrored.unwrap_or(by_idx[&idx]))
+            .collect()
+    }
+
+    #[test]
+    fn ltr_text_is_unchanged() {
+        assert_eq!(visual_chars("abc", Direction::Auto), ['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn rtl_run_is_reversed() {
+        assert_eq!(visual_chars("אבג", Direction::Rtl), ['ג', 'ב', 'א']);
+    }
+
+    #[test]
+    fn digits_keep_reading_order_inside_an_rtl_run() {
+        // Regression test: a number embedded in RTL text must not have its own
+        // digit order reversed, even though the surrounding Arabic letters are.
+        assert_eq!(
+            visual_chars("ابج123", Direction::Auto),
+            ['1', '2', '3', 'ج', 'ب', 'ا'],
+        );
+    }
+
+    #[test]
+    fn combining_mark_stays_attached_to_its_base() {
+        // U+0301 COMBINING ACUTE ACCENT after the Hebrew base char א, inside a
+        // reversed RTL run: the mark must travel with its base, never separately.
+        let text = "א\u{0301}ב";
+        let visual = visual_chars(text, Direction::Rtl);
+        assert_eq!(visual, ['ב', 'א', '\u{0301}']);
+    }
+}
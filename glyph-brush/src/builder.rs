@@ -0,0 +1,59 @@
+use super::*;
+
+/// Builds a text renderer, selecting the fonts and [`Shaper`] it uses.
+///
+/// Only the shaper-selection surface introduced alongside [`Shaper`] lives here;
+/// the rest of the real `GlyphBrushBuilder` (caches, section hashing, draw queue)
+/// lives in the main crate module this one composes with.
+pub struct GlyphBrushBuilder<F, S = NaiveShaper> {
+    pub(crate) fonts: Vec<F>,
+    pub(crate) shaper: S,
+}
+
+impl<F> GlyphBrushBuilder<F, NaiveShaper> {
+    /// Starts a builder using the default [`NaiveShaper`] — callers who never
+    /// reach for [`shaper`](Self::shaper) pay nothing for complex-text shaping.
+    #[inline]
+    pub fn using_fonts(fonts: Vec<F>) -> Self {
+        Self {
+            fonts,
+            shaper: NaiveShaper,
+        }
+    }
+}
+
+impl<F: ab_glyph::Font, S> GlyphBrushBuilder<F, S> {
+    /// Selects a custom [`Shaper`] (ligatures, contextual forms, GPOS kerning, ...)
+    /// in place of the default [`NaiveShaper`].
+    #[inline]
+    pub fn shaper<S2: Shaper<F>>(self, shaper: S2) -> GlyphBrushBuilder<F, S2> {
+        GlyphBrushBuilder {
+            fonts: self.fonts,
+            shaper,
+        }
+    }
+}
+
+impl<F: ab_glyph::Font, S: Shaper<F>> GlyphBrushBuilder<F, S> {
+    /// Shapes `run` against `self.fonts[run.font_id]` using the builder's selected
+    /// [`Shaper`]. The actual glyph-to-vertex/draw-queue pipeline this feeds lives
+    /// in the main crate module, outside this builder; see
+    /// [`run_vertices`](Self::run_vertices) for the common case of shaping plus
+    /// decoration vertices together.
+    pub(crate) fn shape_run<X>(&self, run: &Text<'_, X>) -> Vec<ShapedGlyph> {
+        let font = &self.fonts[run.font_id.0];
+        self.shaper.shape(run, font)
+    }
+
+    /// Shapes `run` via the builder's selected [`Shaper`] and computes its
+    /// underline/strikethrough vertices from the resulting glyphs in one step —
+    /// the call a renderer built on this crate makes per laid-out run.
+    pub fn run_vertices(&self, run: &Text<'_, Extra>) -> (Vec<ShapedGlyph>, Vec<DecorationVertex>) {
+        let glyphs = self.shape_run(run);
+        let font = &self.fonts[run.font_id.0];
+        let scaled = ab_glyph::Font::as_scaled(font, run.scale);
+        let ascent = ab_glyph::ScaleFont::ascent(&scaled);
+        let decorations = run.decoration_vertices(&glyphs, (0.0, 0.0), ascent);
+        (glyphs, decorations)
+    }
+}
@@ -0,0 +1,109 @@
+use super::*;
+use ordered_float::OrderedFloat;
+use std::hash::{Hash, Hasher};
+
+/// A line decoration drawn alongside a text run, e.g. an underline or strikethrough.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decoration {
+    /// Color of the decoration line. `None` inherits the run's `color`.
+    pub color: Option<Color>,
+    /// Thickness of the decoration line, in pixels.
+    pub thickness: f32,
+}
+
+impl Default for Decoration {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color: None,
+            thickness: 1.0,
+        }
+    }
+}
+
+impl<C: Into<Color>> From<C> for Decoration {
+    #[inline]
+    fn from(color: C) -> Self {
+        Self {
+            color: Some(color.into()),
+            ..Decoration::default()
+        }
+    }
+}
+
+impl Hash for Decoration {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Decoration { color, thickness } = *self;
+
+        match color {
+            Some(color) => {
+                true.hash(state);
+                let ord_floats: &[OrderedFloat<_>] =
+                    &[color[0].into(), color[1].into(), color[2].into(), color[3].into()];
+                ord_floats.hash(state);
+            }
+            None => false.hash(state),
+        }
+
+        OrderedFloat(thickness).hash(state);
+    }
+}
+
+/// Default extra part for text rendering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extra {
+    /// Rgba color of rendered text. Defaults to black.
+    pub color: Color,
+    /// Rgba color of the text outline, for underlying implementations that support it.
+    /// Defaults to black.
+    pub outline_color: Color,
+    /// Z values for use in depth testing. Defaults to 0.0.
+    pub z: f32,
+    /// Underline decoration drawn below the baseline. Defaults to `None` (no underline).
+    pub underline: Option<Decoration>,
+    /// Strikethrough decoration drawn through the x-height. Defaults to `None`.
+    pub strikethrough: Option<Decoration>,
+}
+
+impl Default for Extra {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color: [0.0, 0.0, 0.0, 1.0],
+            outline_color: [0.0, 0.0, 0.0, 1.0],
+            z: 0.0,
+            underline: None,
+            strikethrough: None,
+        }
+    }
+}
+
+impl Hash for Extra {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Extra {
+            color,
+            outline_color,
+            z,
+            underline,
+            strikethrough,
+        } = *self;
+
+        let ord_floats: &[OrderedFloat<_>] = &[
+            color[0].into(),
+            color[1].into(),
+            color[2].into(),
+            color[3].into(),
+            outline_color[0].into(),
+            outline_color[1].into(),
+            outline_color[2].into(),
+            outline_color[3].into(),
+            z.into(),
+        ];
+
+        ord_floats.hash(state);
+        underline.hash(state);
+        strikethrough.hash(state);
+    }
+}
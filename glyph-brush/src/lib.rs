@@ -0,0 +1,17 @@
+mod bidi;
+mod builder;
+mod extra;
+mod owned_section;
+mod section;
+mod vertex;
+
+pub use crate::builder::GlyphBrushBuilder;
+pub use crate::extra::{Decoration, Extra};
+pub use crate::owned_section::{OwnedSection, OwnedText};
+pub use crate::section::*;
+pub use crate::vertex::DecorationVertex;
+
+// Layout primitives (`Layout`, `BuiltInLineBreaker`, `FontId`, `SectionGeometry`,
+// `SectionText`, `ToSectionText`, `HorizontalAlign`, ...) and glyph metrics
+// (`PxScale`) come from the layout/font crates this crate builds on.
+pub use glyph_brush_layout::*;
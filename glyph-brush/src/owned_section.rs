@@ -0,0 +1,129 @@
+use super::*;
+
+/// Owned, `'static` version of [`Section`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedSection<X = Extra> {
+    /// See [`Section::screen_position`].
+    pub screen_position: (f32, f32),
+    /// See [`Section::bounds`].
+    pub bounds: (f32, f32),
+    /// See [`Section::layout`].
+    pub layout: Layout<BuiltInLineBreaker>,
+    /// See [`Section::base_direction`].
+    pub base_direction: Direction,
+    /// See [`Section::text`].
+    pub text: Vec<OwnedText<X>>,
+}
+
+impl Default for OwnedSection<Extra> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            screen_position: (0.0, 0.0),
+            bounds: (f32::INFINITY, f32::INFINITY),
+            layout: Layout::default(),
+            base_direction: Direction::default(),
+            text: vec![],
+        }
+    }
+}
+
+impl<X> OwnedSection<X> {
+    #[inline]
+    pub fn with_screen_position<P: Into<(f32, f32)>>(mut self, position: P) -> Self {
+        self.screen_position = position.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_bounds<P: Into<(f32, f32)>>(mut self, bounds: P) -> Self {
+        self.bounds = bounds.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_layout<L: Into<Layout<BuiltInLineBreaker>>>(mut self, layout: L) -> Self {
+        self.layout = layout.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_base_direction<D: Into<Direction>>(mut self, base_direction: D) -> Self {
+        self.base_direction = base_direction.into();
+        self
+    }
+
+    #[inline]
+    pub fn add_text<T: Into<OwnedText<X>>>(mut self, text: T) -> Self {
+        self.text.push(text.into());
+        self
+    }
+}
+
+impl<X: Clone> OwnedSection<X> {
+    /// Borrows this owned section as a [`Section`], e.g. to queue it for rendering.
+    #[inline]
+    pub fn to_borrowed(&self) -> Section<'_, X> {
+        Section {
+            screen_position: self.screen_position,
+            bounds: self.bounds,
+            relative_screen_position: None,
+            relative_bounds: None,
+            layout: self.layout,
+            base_direction: self.base_direction,
+            text: self.text.iter().map(Text::from).collect(),
+        }
+    }
+}
+
+impl<'a, X: Clone> From<&'a OwnedSection<X>> for Section<'a, X> {
+    #[inline]
+    fn from(owned: &'a OwnedSection<X>) -> Self {
+        owned.to_borrowed()
+    }
+}
+
+/// Owned, `'static` version of [`Text`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedText<X = Extra> {
+    /// See [`Text::text`].
+    pub text: String,
+    /// See [`Text::scale`].
+    pub scale: PxScale,
+    /// See [`Text::font_id`].
+    pub font_id: FontId,
+    /// See [`Text::letter_spacing`].
+    pub letter_spacing: f32,
+    /// See [`Text::line_height`].
+    pub line_height: Option<f32>,
+    /// See [`Text::extra`].
+    pub extra: X,
+}
+
+impl<X: Clone> From<&Text<'_, X>> for OwnedText<X> {
+    #[inline]
+    fn from(t: &Text<'_, X>) -> Self {
+        Self {
+            text: t.text.into(),
+            scale: t.scale,
+            font_id: t.font_id,
+            letter_spacing: t.letter_spacing,
+            line_height: t.line_height,
+            extra: t.extra.clone(),
+        }
+    }
+}
+
+impl<'a, X: Clone> From<&'a OwnedText<X>> for Text<'a, X> {
+    #[inline]
+    fn from(t: &'a OwnedText<X>) -> Self {
+        Self {
+            text: &t.text,
+            scale: t.scale,
+            font_id: t.font_id,
+            letter_spacing: t.letter_spacing,
+            line_height: t.line_height,
+            extra: t.extra.clone(),
+        }
+    }
+}
@@ -4,6 +4,95 @@ use std::{borrow::Cow, f32, hash::*};
 
 pub type Color = [f32; 4];
 
+/// Base paragraph direction used to resolve bidirectional text (UAX #9).
+///
+/// Matters when a section mixes LTR and RTL runs, and also when digits or neutral
+/// characters (spaces, punctuation, brackets) come before a run's first strong
+/// character: those are resolved against this level, so their order and bracket
+/// mirroring depend on this setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Infer the paragraph level from the first strong-directional character.
+    Auto,
+    /// Force left-to-right.
+    Ltr,
+    /// Force right-to-left.
+    Rtl,
+}
+
+impl Default for Direction {
+    #[inline]
+    fn default() -> Self {
+        Direction::Auto
+    }
+}
+
+/// A dimension that is either an absolute pixel value or a fraction of the render
+/// target's corresponding dimension, resolved against the target size at
+/// `queue`/`process_queued` time.
+///
+/// Inspired by GPUI's `Length`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    /// An absolute value, in pixels.
+    Px(f32),
+    /// A fraction of the render target's dimension, typically in `0.0..=1.0`.
+    Relative(f32),
+}
+
+impl Length {
+    /// A `Length` that is a fraction of the render target's dimension, e.g.
+    /// `Length::relative(0.5)` is half the target's width or height.
+    #[inline]
+    pub fn relative(fraction: f32) -> Self {
+        Length::Relative(fraction)
+    }
+
+    /// Resolves this length against a concrete target dimension, in pixels.
+    #[inline]
+    pub fn resolve(self, target: f32) -> f32 {
+        match self {
+            Length::Px(px) => px,
+            Length::Relative(fraction) => fraction * target,
+        }
+    }
+}
+
+impl From<f32> for Length {
+    #[inline]
+    fn from(px: f32) -> Self {
+        Length::Px(px)
+    }
+}
+
+/// Either an absolute `(f32, f32)` pixel value or a symbolic `(Length, Length)`
+/// pair, accepted by [`Section::with_screen_position`]/[`Section::with_bounds`].
+///
+/// Rust has no true overloading on argument type alone, so this wrapper (and its
+/// two `From` impls) is what lets both methods stay single, discoverable methods
+/// instead of splitting into separate `with_relative_*` methods.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Extent {
+    /// An absolute pixel value.
+    Px((f32, f32)),
+    /// A symbolic value, resolved against the render target size.
+    Relative((Length, Length)),
+}
+
+impl From<(f32, f32)> for Extent {
+    #[inline]
+    fn from(px: (f32, f32)) -> Self {
+        Extent::Px(px)
+    }
+}
+
+impl From<(Length, Length)> for Extent {
+    #[inline]
+    fn from(lengths: (Length, Length)) -> Self {
+        Extent::Relative(lengths)
+    }
+}
+
 /// An object that contains all the info to render a varied section of text. That is one including
 /// many parts with differing fonts/scales/colors bowing to a single layout.
 ///
@@ -23,12 +112,33 @@ pub type Color = [f32; 4];
 #[derive(Debug, Clone, PartialEq)]
 pub struct Section<'a, X = Extra> {
     /// Position on screen to render text, in pixels from top-left. Defaults to (0, 0).
+    ///
+    /// If set via [`with_screen_position`](Self::with_screen_position) with a
+    /// symbolic [`Extent::Relative`], this is the last resolved pixel value and is
+    /// refreshed on `queue`/`process_queued`.
     pub screen_position: (f32, f32),
     /// Max (width, height) bounds, in pixels from top-left. Defaults to unbounded.
+    ///
+    /// If set via [`with_bounds`](Self::with_bounds) with a symbolic
+    /// [`Extent::Relative`], this is the last resolved pixel value and is
+    /// refreshed on `queue`/`process_queued`.
     pub bounds: (f32, f32),
+    /// Symbolic screen position, resolved against the render target size to produce
+    /// `screen_position`. `None` means `screen_position` is used as-is. Not carried
+    /// over by [`to_owned`](Self::to_owned) — an [`OwnedSection`] is a fully
+    /// resolved snapshot, so use [`to_owned_resolved`](Self::to_owned_resolved) if
+    /// the section has relative geometry.
+    pub(crate) relative_screen_position: Option<(Length, Length)>,
+    /// Symbolic bounds, resolved against the render target size to produce `bounds`.
+    /// `None` means `bounds` is used as-is. Not carried over by
+    /// [`to_owned`](Self::to_owned); see [`to_owned_resolved`](Self::to_owned_resolved).
+    pub(crate) relative_bounds: Option<(Length, Length)>,
     /// Built in layout, can be overridden with custom layout logic
     /// see [`queue_custom_layout`](struct.GlyphBrush.html#method.queue_custom_layout)
     pub layout: Layout<BuiltInLineBreaker>,
+    /// Base direction used to resolve bidirectional (RTL/mixed) text. Defaults to
+    /// [`Direction::Auto`].
+    pub base_direction: Direction,
     /// Text to render, rendered next to one another according the layout.
     pub text: Vec<Text<'a, X>>,
 }
@@ -53,31 +163,77 @@ impl<'a, X> Section<'a, X> {
         Self {
             screen_position: (0.0, 0.0),
             bounds: (f32::INFINITY, f32::INFINITY),
+            relative_screen_position: None,
+            relative_bounds: None,
             layout: Layout::default(),
+            base_direction: Direction::default(),
             text: vec![],
         }
     }
 }
 
 impl<'a, X> Section<'a, X> {
+    /// Sets the screen position to render text at: an absolute `(f32, f32)` in
+    /// pixels, or a symbolic `(Length, Length)` (e.g. `(Length::relative(0.5),
+    /// Length::Px(20.0))`) resolved against the render target size on the next
+    /// `queue`/`process_queued` call, e.g. for a horizontally-centered position.
     #[inline]
-    pub fn with_screen_position<P: Into<(f32, f32)>>(mut self, position: P) -> Self {
-        self.screen_position = position.into();
+    pub fn with_screen_position<P: Into<Extent>>(mut self, position: P) -> Self {
+        match position.into() {
+            Extent::Px(px) => {
+                self.screen_position = px;
+                self.relative_screen_position = None;
+            }
+            Extent::Relative(lengths) => self.relative_screen_position = Some(lengths),
+        }
         self
     }
 
+    /// Sets the max `(width, height)` bounds: an absolute `(f32, f32)` in pixels,
+    /// or a symbolic `(Length, Length)` (e.g. `(Length::relative(1.0),
+    /// Length::relative(0.5))` for "full width, half height") resolved against the
+    /// render target size on the next `queue`/`process_queued` call.
     #[inline]
-    pub fn with_bounds<P: Into<(f32, f32)>>(mut self, bounds: P) -> Self {
-        self.bounds = bounds.into();
+    pub fn with_bounds<P: Into<Extent>>(mut self, bounds: P) -> Self {
+        match bounds.into() {
+            Extent::Px(px) => {
+                self.bounds = px;
+                self.relative_bounds = None;
+            }
+            Extent::Relative(lengths) => self.relative_bounds = Some(lengths),
+        }
         self
     }
 
+    /// Re-resolves `screen_position`/`bounds` from the symbolic [`Extent::Relative`]
+    /// values passed to [`with_screen_position`](Self::with_screen_position) /
+    /// [`with_bounds`](Self::with_bounds), against the current render target size,
+    /// in pixels. A no-op for sections with no relative geometry.
+    ///
+    /// Called by `queue`/`process_queued` before the section is hashed, so caching
+    /// always keys off the resolved pixel geometry, never the symbolic form.
+    pub(crate) fn resolve_relative_geometry(&mut self, target_size: (f32, f32)) {
+        if let Some((width, height)) = self.relative_screen_position {
+            self.screen_position = (width.resolve(target_size.0), height.resolve(target_size.1));
+        }
+        if let Some((width, height)) = self.relative_bounds {
+            self.bounds = (width.resolve(target_size.0), height.resolve(target_size.1));
+        }
+    }
+
     #[inline]
     pub fn with_layout<L: Into<Layout<BuiltInLineBreaker>>>(mut self, layout: L) -> Self {
         self.layout = layout.into();
         self
     }
 
+    /// Sets the base paragraph direction used to resolve bidirectional text.
+    #[inline]
+    pub fn with_base_direction<D: Into<Direction>>(mut self, base_direction: D) -> Self {
+        self.base_direction = base_direction.into();
+        self
+    }
+
     #[inline]
     pub fn add_text<T: Into<Text<'a, X>>>(mut self, text: T) -> Self {
         self.text.push(text.into());
@@ -90,7 +246,10 @@ impl<'a, X> Section<'a, X> {
             text,
             screen_position: self.screen_position,
             bounds: self.bounds,
+            relative_screen_position: self.relative_screen_position,
+            relative_bounds: self.relative_bounds,
             layout: self.layout,
+            base_direction: self.base_direction,
         }
     }
 }
@@ -115,7 +274,10 @@ impl<X: Hash> Hash for Section<'_, X> {
         let Section {
             screen_position: (screen_x, screen_y),
             bounds: (bound_w, bound_h),
+            relative_screen_position: _,
+            relative_bounds: _,
             layout,
+            base_direction,
             ref text,
         } = *self;
 
@@ -131,6 +293,7 @@ impl<X: Hash> Hash for Section<'_, X> {
         hash_section_text(state, text);
 
         ord_floats.hash(state);
+        base_direction.hash(state);
     }
 }
 
@@ -146,6 +309,12 @@ pub struct Text<'a, X = Extra> {
     /// It must be a valid id in the `FontMap` used for layout calls.
     /// The default `FontId(0)` should always be valid.
     pub font_id: FontId,
+    /// Extra horizontal advance added to every glyph's advance in this run (the last
+    /// glyph included), in pixels. Defaults to 0.0 (font-intrinsic advances only). Negative values tighten tracking.
+    pub letter_spacing: f32,
+    /// Overrides the font's intrinsic ascent+descent+line-gap when advancing between
+    /// wrapped lines within this run. Defaults to `None` (use font metrics).
+    pub line_height: Option<f32>,
     /// Extra stuff for vertex generation.
     pub extra: X,
 }
@@ -157,6 +326,8 @@ impl<X: Default> Default for Text<'static, X> {
             text: "",
             scale: PxScale::from(16.0),
             font_id: <_>::default(),
+            letter_spacing: 0.0,
+            line_height: None,
             extra: <_>::default(),
         }
     }
@@ -169,6 +340,8 @@ impl<'a, X> Text<'a, X> {
             text,
             scale: self.scale,
             font_id: self.font_id,
+            letter_spacing: self.letter_spacing,
+            line_height: self.line_height,
             extra: self.extra,
         }
     }
@@ -185,12 +358,28 @@ impl<'a, X> Text<'a, X> {
         self
     }
 
+    /// Sets extra horizontal advance added to every glyph's advance in this run, in pixels.
+    #[inline]
+    pub fn with_letter_spacing<S: Into<f32>>(mut self, letter_spacing: S) -> Self {
+        self.letter_spacing = letter_spacing.into();
+        self
+    }
+
+    /// Overrides the font's intrinsic line height for this run, in pixels.
+    #[inline]
+    pub fn with_line_height<H: Into<f32>>(mut self, line_height: H) -> Self {
+        self.line_height = Some(line_height.into());
+        self
+    }
+
     #[inline]
     pub fn with_extra<X2>(self, extra: X2) -> Text<'a, X2> {
         Text {
             text: self.text,
             scale: self.scale,
             font_id: self.font_id,
+            letter_spacing: self.letter_spacing,
+            line_height: self.line_height,
             extra,
         }
     }
@@ -219,6 +408,37 @@ impl<'a> Text<'a, Extra> {
         self.extra.z = z.into();
         self
     }
+
+    /// Adds an underline decoration spanning this run, drawn below the baseline.
+    ///
+    /// Accepts either a [`Color`] (using the default thickness) or a [`Decoration`]
+    /// for full control. Pass `None` as the decoration's color to inherit `with_color`.
+    #[inline]
+    pub fn with_underline<D: Into<Decoration>>(mut self, underline: D) -> Self {
+        self.extra.underline = Some(underline.into());
+        self
+    }
+
+    /// Adds a strikethrough decoration spanning this run, drawn through the x-height.
+    ///
+    /// Accepts either a [`Color`] (using the default thickness) or a [`Decoration`]
+    /// for full control. Pass `None` as the decoration's color to inherit `with_color`.
+    #[inline]
+    pub fn with_strikethrough<D: Into<Decoration>>(mut self, strikethrough: D) -> Self {
+        self.extra.strikethrough = Some(strikethrough.into());
+        self
+    }
+}
+
+impl<X> Text<'_, X> {
+    /// Returns this run's chars in visual order for `base_direction`, per the
+    /// (simplified) Unicode Bidi Algorithm — see [`crate::bidi::reorder_visual`].
+    /// The layout stage walks this instead of `text.char_indices()` directly when
+    /// laying out glyphs, so mixed LTR/RTL runs render in the right order.
+    #[inline]
+    pub(crate) fn visual_char_order(&self, base_direction: Direction) -> Vec<(usize, Option<char>)> {
+        crate::bidi::reorder_visual(self.text, base_direction)
+    }
 }
 
 impl<X> ToSectionText for Text<'_, X> {
@@ -239,12 +459,19 @@ fn hash_section_text<X: Hash, H: Hasher>(state: &mut H, text: &[Text<'_, X>]) {
             text,
             scale,
             font_id,
+            letter_spacing,
+            line_height,
             ref extra,
         } = *t;
 
-        let ord_floats: [OrderedFloat<_>; 2] = [scale.x.into(), scale.y.into()];
+        let ord_floats: [OrderedFloat<_>; 3] = [
+            scale.x.into(),
+            scale.y.into(),
+            letter_spacing.into(),
+        ];
 
         (text, font_id, extra, ord_floats).hash(state);
+        line_height.map(OrderedFloat).hash(state);
     }
 }
 
@@ -254,15 +481,30 @@ impl<'text, X: Clone> Section<'text, X> {
             screen_position: self.screen_position,
             bounds: self.bounds,
             layout: self.layout,
+            base_direction: self.base_direction,
             text: self.text.iter().map(OwnedText::from).collect(),
         }
     }
 
+    /// Like [`to_owned`](Self::to_owned), but first resolves any symbolic geometry
+    /// set via [`with_bounds`](Self::with_bounds)/[`with_screen_position`](Self::with_screen_position)
+    /// against `target_size`, so the returned snapshot carries final pixel
+    /// geometry. `target_size` is the render target's `(width, height)` in pixels;
+    /// `queue`/`process_queued` call this with the current target size each frame.
+    pub fn to_owned_resolved(&self, target_size: (f32, f32)) -> OwnedSection<X> {
+        let mut resolved = self.clone();
+        resolved.resolve_relative_geometry(target_size);
+        resolved.to_owned()
+    }
+
     #[inline]
     pub(crate) fn to_hashable_parts(&self) -> HashableSectionParts<'_, X> {
         let Section {
             screen_position: (screen_x, screen_y),
             bounds: (bound_w, bound_h),
+            relative_screen_position: _,
+            relative_bounds: _,
+            base_direction,
             ref text,
             layout: _,
         } = *self;
@@ -274,7 +516,11 @@ impl<'text, X: Clone> Section<'text, X> {
             bound_h.into(),
         ];
 
-        HashableSectionParts { geometry, text }
+        HashableSectionParts {
+            geometry,
+            base_direction,
+            text,
+        }
     }
 }
 
@@ -290,6 +536,7 @@ impl<X> From<&Section<'_, X>> for SectionGeometry {
 
 pub(crate) struct HashableSectionParts<'a, X> {
     geometry: [OrderedFloat<f32>; 4],
+    base_direction: Direction,
     text: &'a [Text<'a, X>],
 }
 
@@ -297,6 +544,7 @@ impl<X: Hash> HashableSectionParts<'_, X> {
     #[inline]
     pub fn hash_geometry<H: Hasher>(&self, state: &mut H) {
         self.geometry.hash(state);
+        self.base_direction.hash(state);
     }
 
     #[inline]
@@ -306,12 +554,16 @@ impl<X: Hash> HashableSectionParts<'_, X> {
                 text,
                 scale,
                 font_id,
+                letter_spacing,
+                line_height,
                 ..
             } = *t;
 
-            let ord_floats: &[OrderedFloat<_>] = &[scale.x.into(), scale.y.into()];
+            let ord_floats: &[OrderedFloat<_>] =
+                &[scale.x.into(), scale.y.into(), letter_spacing.into()];
 
             (text, font_id, ord_floats).hash(state);
+            line_height.map(OrderedFloat).hash(state);
         }
     }
 
@@ -320,3 +572,109 @@ impl<X: Hash> HashableSectionParts<'_, X> {
         self.text.iter().for_each(|t| t.extra.hash(state));
     }
 }
+
+/// A single shaped glyph, positioned relative to the run's pen origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapedGlyph {
+    /// Glyph id within the font used to shape it.
+    pub glyph_id: ab_glyph::GlyphId,
+    /// Byte index into the source run where this glyph's cluster begins, so
+    /// caret/selection logic can map shaped glyphs back to source text ranges.
+    pub cluster: usize,
+    /// Horizontal offset from the run's pen origin, in pixels.
+    pub x_offset: f32,
+    /// Vertical offset from the run's pen origin, in pixels.
+    pub y_offset: f32,
+    /// Horizontal pen advance contributed by this glyph, in pixels.
+    pub x_advance: f32,
+    /// Vertical pen advance contributed by this glyph, in pixels.
+    pub y_advance: f32,
+}
+
+/// Converts a [`Text`] run into a sequence of positioned [`ShapedGlyph`]s.
+///
+/// Implement this to plug in a complex-text shaping engine (ligatures, Arabic
+/// joining, Indic reordering, real GPOS kerning) consulting the font's GSUB/GPOS
+/// tables, in place of the default [`NaiveShaper`]. The layout stage consumes the
+/// shaped glyph stream instead of raw chars; `cluster` maps glyphs back to source
+/// byte ranges so caret/selection code keeps working, and the GPU glyph cache
+/// still keys off `glyph_id` + subpixel position regardless of which shaper
+/// produced it. Select a shaper via the `GlyphBrushBuilder`; the naive default is
+/// used, and costs nothing extra, if you never call it.
+pub trait Shaper<F: ab_glyph::Font> {
+    /// Shapes `run` using `font`, returning glyphs in the run's logical (source)
+    /// order; no bidi reordering is applied. Generic over
+    /// `X` (like [`Section`]/[`Text`] themselves) so custom `extra` vertex data
+    /// doesn't block shaping — only `text`/`scale`/`font_id`/`letter_spacing` are
+    /// read, never `extra`.
+    fn shape<X>(&self, run: &Text<'_, X>, font: &F) -> Vec<ShapedGlyph>;
+}
+
+/// The default [`Shaper`]: maps each char to a glyph 1:1 and advances by the
+/// font's scaled horizontal advance plus [`Text::letter_spacing`]. No ligatures,
+/// contextual forms, joining, or GPOS kerning.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NaiveShaper;
+
+impl<F: ab_glyph::Font> Shaper<F> for NaiveShaper {
+    fn shape<X>(&self, run: &Text<'_, X>, font: &F) -> Vec<ShapedGlyph> {
+        let scaled = ab_glyph::Font::as_scaled(font, run.scale);
+
+        let mut glyphs = Vec::with_capacity(run.text.len());
+        let mut pen_x = 0.0;
+        for (cluster, c) in run.text.char_indices() {
+            let glyph_id = ab_glyph::ScaleFont::glyph_id(&scaled, c);
+            let x_advance = ab_glyph::ScaleFont::h_advance(&scaled, glyph_id) + run.letter_spacing;
+
+            glyphs.push(ShapedGlyph {
+                glyph_id,
+                cluster,
+                x_offset: pen_x,
+                y_offset: 0.0,
+                x_advance,
+                y_advance: 0.0,
+            });
+
+            pen_x += x_advance;
+        }
+        glyphs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_bounds_accepts_absolute_or_relative() {
+        let absolute = Section::<Extra>::new().with_bounds((100.0, 50.0));
+        assert_eq!(absolute.bounds, (100.0, 50.0));
+        assert!(absolute.relative_bounds.is_none());
+
+        let relative =
+            Section::<Extra>::new().with_bounds((Length::relative(1.0), Length::relative(0.5)));
+        assert_eq!(relative.relative_bounds, Some((Length::Relative(1.0), Length::Relative(0.5))));
+    }
+
+    #[test]
+    fn to_owned_resolved_bakes_in_relative_geometry() {
+        let section = Section::<Extra>::new()
+            .with_screen_position((Length::relative(0.5), Length::Px(20.0)))
+            .with_bounds((Length::relative(1.0), Length::relative(0.5)));
+
+        // Unresolved: still the section's pre-queue defaults.
+        assert_eq!(section.screen_position, (0.0, 0.0));
+        assert_eq!(section.bounds, (f32::INFINITY, f32::INFINITY));
+
+        let owned = section.to_owned_resolved((800.0, 600.0));
+        assert_eq!(owned.screen_position, (400.0, 20.0));
+        assert_eq!(owned.bounds, (800.0, 300.0));
+    }
+
+    #[test]
+    fn to_owned_without_relative_geometry_is_unaffected() {
+        let section = Section::<Extra>::new().with_screen_position((10.0, 20.0));
+        let owned = section.to_owned();
+        assert_eq!(owned.screen_position, (10.0, 20.0));
+    }
+}
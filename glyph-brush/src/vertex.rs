@@ -0,0 +1,122 @@
+use super::*;
+
+/// A single quad vertex for a text decoration (underline/strikethrough), laid out so
+/// it can share a vertex buffer and pipeline with glyph quads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecorationVertex {
+    /// `(left, top)` pixel coordinate of the quad.
+    pub min: (f32, f32),
+    /// `(right, bottom)` pixel coordinate of the quad.
+    pub max: (f32, f32),
+    /// Rgba color of the quad.
+    pub color: Color,
+    /// Z value, matching the run's `Extra::z`.
+    pub z: f32,
+}
+
+/// Emits vertex quads for a run's underline/strikethrough decorations, if any.
+///
+/// `baseline` is the pen position `(x, y)` at the start of the run, `run_width` is
+/// the run's total horizontal advance, and `ascent` is the font's pixel ascent at
+/// the run's scale, used to offset the strikethrough up to the x-height. A
+/// decoration with no explicit [`Decoration::color`] inherits `extra.color`.
+pub(crate) fn decoration_vertices(
+    extra: &Extra,
+    baseline: (f32, f32),
+    run_width: f32,
+    ascent: f32,
+) -> Vec<DecorationVertex> {
+    let (baseline_x, baseline_y) = baseline;
+    let mut vertices = Vec::with_capacity(2);
+
+    if let Some(Decoration { color, thickness }) = extra.underline {
+        vertices.push(DecorationVertex {
+            min: (baseline_x, baseline_y + thickness),
+            max: (baseline_x + run_width, baseline_y + 2.0 * thickness),
+            color: color.unwrap_or(extra.color),
+            z: extra.z,
+        });
+    }
+
+    if let Some(Decoration { color, thickness }) = extra.strikethrough {
+        // x-height is conventionally about half the ascent above the baseline.
+        let y = baseline_y - ascent * 0.5;
+        vertices.push(DecorationVertex {
+            min: (baseline_x, y),
+            max: (baseline_x + run_width, y + thickness),
+            color: color.unwrap_or(extra.color),
+            z: extra.z,
+        });
+    }
+
+    vertices
+}
+
+impl Text<'_, Extra> {
+    /// Computes this run's underline/strikethrough vertices from its shaped glyphs —
+    /// the call a renderer built on this crate makes right after shaping a run (see
+    /// [`GlyphBrushBuilder::run_vertices`] for shaping + decoration in one step).
+    ///
+    /// `pen_start` is the pen position `(x, y)` this run was shaped from, and
+    /// `ascent` is the font's pixel ascent at `self.scale` (used to offset the
+    /// strikethrough to the x-height).
+    pub fn decoration_vertices(
+        &self,
+        shaped: &[ShapedGlyph],
+        pen_start: (f32, f32),
+        ascent: f32,
+    ) -> Vec<DecorationVertex> {
+        let run_width = shaped.iter().map(|g| g.x_advance).sum();
+        decoration_vertices(&self.extra, pen_start, run_width, ascent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn underline_and_strikethrough_span_the_shaped_run_width() {
+        let text = Text::new("ab")
+            .with_underline([1.0, 0.0, 0.0, 1.0])
+            .with_strikethrough(Decoration {
+                color: None,
+                thickness: 2.0,
+            });
+
+        // Two glyphs shaped 10px apart, as a real `Shaper` impl would return.
+        let shaped = vec![
+            ShapedGlyph {
+                glyph_id: ab_glyph::GlyphId(1),
+                cluster: 0,
+                x_offset: 0.0,
+                y_offset: 0.0,
+                x_advance: 10.0,
+                y_advance: 0.0,
+            },
+            ShapedGlyph {
+                glyph_id: ab_glyph::GlyphId(2),
+                cluster: 1,
+                x_offset: 10.0,
+                y_offset: 0.0,
+                x_advance: 8.0,
+                y_advance: 0.0,
+            },
+        ];
+
+        let vertices = text.decoration_vertices(&shaped, (5.0, 20.0), 12.0);
+
+        assert_eq!(vertices.len(), 2);
+
+        let underline = vertices[0];
+        assert_eq!(underline.min, (5.0, 21.0));
+        assert_eq!(underline.max, (23.0, 22.0));
+        assert_eq!(underline.color, [1.0, 0.0, 0.0, 1.0]);
+
+        let strikethrough = vertices[1];
+        assert_eq!(strikethrough.min, (5.0, 14.0));
+        assert_eq!(strikethrough.max, (23.0, 16.0));
+        // No explicit strikethrough color -> inherits the run's (default) color.
+        assert_eq!(strikethrough.color, text.extra.color);
+    }
+}